@@ -1,9 +1,50 @@
 use prometheus::{Counter, Gauge, Histogram, Registry};
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+const DEFAULT_RHYTHM_FREQUENCY: f64 = 0.038;
+const DEFAULT_SENSITIVITY: f64 = 1.5;
+
+/// Rhythm detector configuration
+#[derive(Debug, Clone)]
+pub struct ArchGuardConfig {
+    /// Expected rhythm frequency in Hz; non-positive values fall back to 0.038
+    pub rhythm_frequency: f64,
+    /// Number of recent samples used as the beat baseline; raised to at least 2
+    pub window_size: usize,
+    /// Standard deviations above the window mean that count as a beat;
+    /// negative values are treated as 0.0, non-finite ones fall back to 1.5
+    pub sensitivity: f64,
+    /// Allowed relative deviation of a beat interval from the expected period;
+    /// negative values are treated as 0.0
+    pub period_tolerance: f64,
+}
+
+impl Default for ArchGuardConfig {
+    fn default() -> Self {
+        Self {
+            rhythm_frequency: DEFAULT_RHYTHM_FREQUENCY,
+            window_size: 256,
+            sensitivity: DEFAULT_SENSITIVITY,
+            period_tolerance: 0.5,
+        }
+    }
+}
+
+/// Event emitted by the rhythm detector
+#[derive(Debug, Clone, PartialEq)]
+pub enum RhythmEvent {
+    /// Signal peak; `interval` is the time since the previous beat
+    Beat { timestamp: f64, interval: Option<f64> },
+    /// Beat whose interval is outside the configured tolerance
+    Anomaly { timestamp: f64, interval: f64 },
+    /// The configured rhythm clock completed a period (not a detected beat)
+    PhaseWrap { timestamp: f64 },
+}
+
 /// ArchGuard Enterprise: circuit-breaker, prometheus, empathy_ratio, rhythm detector
 pub struct ArchGuard {
     // Circuit breaker
@@ -29,6 +70,10 @@ pub struct ArchGuard {
 
 impl ArchGuard {
     pub fn new() -> Self {
+        Self::with_config(ArchGuardConfig::default())
+    }
+    
+    pub fn with_config(config: ArchGuardConfig) -> Self {
         let registry = Registry::new();
         
         let request_counter = Counter::new(
@@ -69,7 +114,7 @@ impl ArchGuard {
             error_counter,
             latency_histogram,
             empathy_ratio,
-            rhythm_detector: RhythmDetector::new(0.038), // 0.038 Hz
+            rhythm_detector: RhythmDetector::new(&config),
             empathy_ratio_value: Arc::new(RwLock::new(0.5)),
         }
     }
@@ -135,7 +180,7 @@ impl ArchGuard {
     
     /// Update empathy ratio (0.0 - 1.0)
     pub async fn update_empathy_ratio(&self, ratio: f64) {
-        let clamped = ratio.max(0.0).min(1.0);
+        let clamped = ratio.clamp(0.0, 1.0);
         {
             let mut value = self.empathy_ratio_value.write().await;
             *value = clamped;
@@ -153,11 +198,22 @@ impl ArchGuard {
         self.circuit_open.load(Ordering::Acquire)
     }
     
-    /// Update rhythm detector
+    /// Update rhythm detector; queues a `PhaseWrap` for every completed period
     pub fn update_rhythm(&mut self, timestamp: f64) {
         self.rhythm_detector.update(timestamp);
     }
     
+    /// Feed a rhythm signal sample; beats and anomalies are queued as events.
+    /// Does not advance the phase, use `update_rhythm` for that.
+    pub fn update_rhythm_signal(&mut self, timestamp: f64, value: f64) {
+        self.rhythm_detector.sample(timestamp, value);
+    }
+    
+    /// Drain rhythm events detected since the last call
+    pub fn take_events(&mut self) -> Vec<RhythmEvent> {
+        std::mem::take(&mut self.rhythm_detector.events)
+    }
+    
     /// Get rhythm phase (0.0 - 1.0)
     pub fn get_rhythm_phase(&self) -> f64 {
         self.rhythm_detector.get_phase()
@@ -175,28 +231,87 @@ impl Default for ArchGuard {
     }
 }
 
-/// Rhythm Detector: detects 0.038 Hz rhythm (~26.3 seconds)
+/// Rhythm Detector: detects 0.038 Hz rhythm (~26.3 seconds) by default
 struct RhythmDetector {
-    frequency: f64, // 0.038 Hz
-    period: f64,    // 1 / frequency
+    period: f64, // 1 / frequency
     last_update: f64,
     phase: f64,
+    
+    // Beat detection over a sliding window of samples
+    window: VecDeque<f64>,
+    window_size: usize,
+    sensitivity: f64,
+    period_tolerance: f64,
+    above_threshold: bool,
+    last_beat: Option<f64>,
+    events: Vec<RhythmEvent>,
 }
 
 impl RhythmDetector {
-    fn new(frequency: f64) -> Self {
+    fn new(config: &ArchGuardConfig) -> Self {
+        let window_size = config.window_size.max(2);
+        let frequency = if config.rhythm_frequency > 0.0 && config.rhythm_frequency.is_finite() {
+            config.rhythm_frequency
+        } else {
+            DEFAULT_RHYTHM_FREQUENCY
+        };
         Self {
-            frequency,
             period: 1.0 / frequency,
             last_update: 0.0,
             phase: 0.0,
+            window: VecDeque::with_capacity(window_size),
+            window_size,
+            sensitivity: if config.sensitivity.is_finite() {
+                config.sensitivity.max(0.0)
+            } else {
+                DEFAULT_SENSITIVITY
+            },
+            period_tolerance: config.period_tolerance.max(0.0),
+            above_threshold: false,
+            last_beat: None,
+            events: Vec::new(),
+        }
+    }
+    
+    fn sample(&mut self, timestamp: f64, value: f64) {
+        // Only detect once the window holds a full baseline
+        if self.window.len() == self.window_size {
+            let n = self.window.len() as f64;
+            let mean = self.window.iter().sum::<f64>() / n;
+            let variance = self.window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+            let threshold = mean + self.sensitivity * variance.sqrt();
+            
+            if value > threshold && !self.above_threshold {
+                // Rising edge: one beat per excursion
+                self.above_threshold = true;
+                let interval = self.last_beat.map(|last| timestamp - last);
+                self.last_beat = Some(timestamp);
+                self.events.push(RhythmEvent::Beat { timestamp, interval });
+                
+                if let Some(interval) = interval {
+                    if (interval - self.period).abs() > self.period * self.period_tolerance {
+                        self.events.push(RhythmEvent::Anomaly { timestamp, interval });
+                    }
+                }
+            } else if value <= mean {
+                self.above_threshold = false;
+            }
+            
+            self.window.pop_front();
         }
+        self.window.push_back(value);
     }
     
     fn update(&mut self, timestamp: f64) {
         if self.last_update > 0.0 {
             let delta = timestamp - self.last_update;
-            self.phase = (self.phase + delta / self.period) % 1.0;
+            let phase = self.phase + delta / self.period;
+            // One event per wrap, timestamped where that period ended
+            for wrap in 1..=(phase.floor() as usize) {
+                let timestamp = self.last_update + (wrap as f64 - self.phase) * self.period;
+                self.events.push(RhythmEvent::PhaseWrap { timestamp });
+            }
+            self.phase = phase % 1.0;
         }
         self.last_update = timestamp;
     }
@@ -224,3 +339,152 @@ impl std::fmt::Display for ArchGuardError {
 }
 
 impl std::error::Error for ArchGuardError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_periodic_signal_beats() {
+        let mut guard = ArchGuard::with_config(ArchGuardConfig {
+            rhythm_frequency: 0.1,
+            window_size: 100,
+            sensitivity: 1.0,
+            period_tolerance: 0.2,
+        });
+
+        // 0.1 Hz sine sampled at 10 Hz for 100 s; the first 10 s fill the window
+        for i in 0..1000 {
+            let t = i as f64 * 0.1;
+            guard.update_rhythm_signal(t, (2.0 * std::f64::consts::PI * 0.1 * t).sin());
+        }
+
+        let events = guard.take_events();
+        let beats = events.iter().filter(|e| matches!(e, RhythmEvent::Beat { .. })).count();
+        let anomalies = events.iter().filter(|e| matches!(e, RhythmEvent::Anomaly { .. })).count();
+        assert_eq!(beats, 9);
+        assert_eq!(anomalies, 0);
+        assert!(guard.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_irregular_beats_report_anomaly() {
+        let mut guard = ArchGuard::with_config(ArchGuardConfig {
+            rhythm_frequency: 0.1,
+            window_size: 50,
+            sensitivity: 1.0,
+            period_tolerance: 0.2,
+        });
+
+        // Spikes 10 s apart, then a 25 s gap
+        let spikes = [100, 200, 300, 550];
+        for i in 0..600 {
+            let value = if spikes.contains(&i) { 1.0 } else { 0.0 };
+            guard.update_rhythm_signal(i as f64 * 0.1, value);
+        }
+
+        let events = guard.take_events();
+        let beats = events.iter().filter(|e| matches!(e, RhythmEvent::Beat { .. })).count();
+        let anomalies: Vec<&RhythmEvent> = events
+            .iter()
+            .filter(|e| matches!(e, RhythmEvent::Anomaly { .. }))
+            .collect();
+        assert_eq!(beats, 4);
+        assert_eq!(anomalies.len(), 1);
+        match anomalies[0] {
+            RhythmEvent::Anomaly { timestamp, interval } => {
+                assert!((timestamp - 55.0).abs() < 1e-9);
+                assert!((interval - 25.0).abs() < 1e-9);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_phase_wrap_events() {
+        let mut guard = ArchGuard::with_config(ArchGuardConfig {
+            rhythm_frequency: 0.1,
+            ..ArchGuardConfig::default()
+        });
+
+        // Phase starts at t = 0.1 and wraps every 10 s
+        for i in 1..=350 {
+            guard.update_rhythm(i as f64 * 0.1);
+        }
+
+        let events = guard.take_events();
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|e| matches!(e, RhythmEvent::PhaseWrap { .. })));
+    }
+
+    #[test]
+    fn test_large_delta_emits_every_wrap() {
+        let mut guard = ArchGuard::with_config(ArchGuardConfig {
+            rhythm_frequency: 0.1,
+            ..ArchGuardConfig::default()
+        });
+        guard.update_rhythm(1.0);
+        guard.update_rhythm(100.0);
+
+        let expected: Vec<RhythmEvent> = (1..=9)
+            .map(|k| RhythmEvent::PhaseWrap { timestamp: 1.0 + k as f64 * 10.0 })
+            .collect();
+        assert_eq!(guard.take_events(), expected);
+        assert!((guard.get_rhythm_phase() - 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_invalid_config_is_sanitized() {
+        let mut guard = ArchGuard::with_config(ArchGuardConfig {
+            rhythm_frequency: 0.0,
+            ..ArchGuardConfig::default()
+        });
+        guard.update_rhythm(1.0);
+        guard.update_rhythm(11.0);
+        let expected = 10.0 * DEFAULT_RHYTHM_FREQUENCY;
+        assert!((guard.get_rhythm_phase() - expected).abs() < 1e-9);
+
+        // Exactly periodic beats are not anomalies with the clamped tolerance
+        let mut guard = ArchGuard::with_config(ArchGuardConfig {
+            rhythm_frequency: 0.1,
+            window_size: 50,
+            period_tolerance: -1.0,
+            ..ArchGuardConfig::default()
+        });
+        for i in 0..100 {
+            let value = if i >= 60 && i % 10 == 0 { 1.0 } else { 0.0 };
+            guard.update_rhythm_signal(i as f64, value);
+        }
+        let events = guard.take_events();
+        assert_eq!(events.len(), 4);
+        assert!(events.iter().all(|e| matches!(e, RhythmEvent::Beat { .. })));
+
+        let mut guard = ArchGuard::with_config(ArchGuardConfig {
+            rhythm_frequency: -0.1,
+            ..ArchGuardConfig::default()
+        });
+        guard.update_rhythm(1.0);
+        guard.update_rhythm(11.0);
+        assert!((guard.get_rhythm_phase() - expected).abs() < 1e-9);
+
+        // NaN sensitivity falls back to the default, negative clamps to 0.0
+        for sensitivity in [f64::NAN, -10.0] {
+            let mut guard = ArchGuard::with_config(ArchGuardConfig {
+                rhythm_frequency: 0.1,
+                window_size: 50,
+                sensitivity,
+                ..ArchGuardConfig::default()
+            });
+            for i in 0..100 {
+                let value = if i >= 60 && i % 10 == 0 { 1.0 } else { 0.0 };
+                guard.update_rhythm_signal(i as f64, value);
+            }
+            let beats = guard
+                .take_events()
+                .iter()
+                .filter(|e| matches!(e, RhythmEvent::Beat { .. }))
+                .count();
+            assert_eq!(beats, 4);
+        }
+    }
+}
@@ -16,11 +16,11 @@ pub mod systems {
             
             // Update velocity based on acceleration
             voxel.velocity_x = (voxel.velocity_x as i16 + voxel.acceleration_x as i16)
-                .max(-128).min(127) as i8;
+                .clamp(-128, 127) as i8;
             voxel.velocity_y = (voxel.velocity_y as i16 + voxel.acceleration_y as i16)
-                .max(-128).min(127) as i8;
+                .clamp(-128, 127) as i8;
             voxel.velocity_z = (voxel.velocity_z as i16 + voxel.acceleration_z as i16)
-                .max(-128).min(127) as i8;
+                .clamp(-128, 127) as i8;
         }
     }
     
@@ -1,27 +1,54 @@
 use crate::voxel::{Genome, Voxel};
-use rand::Rng;
-use serde::{Deserialize, Serialize};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 /// NextGen Evolution: combine + mutate + fitness
+///
+/// Cloning copies the RNG state too, so a clone replays the same random
+/// choices as the original from that point on (also for `new()` engines).
 #[derive(Clone)]
 pub struct EvolutionEngine {
     pub mutation_rate: f64,
     pub crossover_rate: f64,
     pub fitness_threshold: f64,
+    rng: StdRng,
+    generation: u64,
+    best_fitness: Option<f64>,
 }
 
 impl EvolutionEngine {
     pub fn new() -> Self {
+        Self::with_rng(StdRng::from_entropy())
+    }
+    
+    /// Create an engine whose runs can be replayed exactly from `seed`
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_rng(StdRng::seed_from_u64(seed))
+    }
+    
+    fn with_rng(rng: StdRng) -> Self {
         Self {
             mutation_rate: 0.1,
             crossover_rate: 0.7,
             fitness_threshold: 0.5,
+            rng,
+            generation: 0,
+            best_fitness: None,
         }
     }
     
+    /// Number of `evolve` calls so far
+    pub fn current_generation(&self) -> u64 {
+        self.generation
+    }
+    
+    /// Highest fitness seen in the last evaluated population
+    pub fn best_fitness(&self) -> Option<f64> {
+        self.best_fitness
+    }
+    
     /// Combine two genomes (crossover)
-    pub fn combine(&self, parent1: &Genome, parent2: &Genome) -> Genome {
-        let mut rng = rand::thread_rng();
+    pub fn combine(&mut self, parent1: &Genome, parent2: &Genome) -> Genome {
         let mut child = Genome::new();
         
         // Combine concepts from both parents
@@ -33,7 +60,7 @@ impl EvolutionEngine {
         // Randomly select concepts for child
         let num_concepts = (all_concepts.len() / 2).min(child.max_concepts);
         for _ in 0..num_concepts {
-            if let Some(concept) = all_concepts.get(rng.gen_range(0..all_concepts.len())) {
+            if let Some(concept) = all_concepts.get(self.rng.gen_range(0..all_concepts.len())) {
                 child.add_concept(concept.clone());
             }
         }
@@ -42,8 +69,8 @@ impl EvolutionEngine {
     }
     
     /// Mutate genome
-    pub fn mutate(&self, genome: &mut Genome) {
-        let mut rng = rand::thread_rng();
+    pub fn mutate(&mut self, genome: &mut Genome) {
+        let rng = &mut self.rng;
         
         if rng.gen_bool(self.mutation_rate) {
             // Add random concept
@@ -99,7 +126,7 @@ impl EvolutionEngine {
     }
     
     /// Evolve a population of voxels
-    pub fn evolve(&self, voxels: &mut [Voxel]) {
+    pub fn evolve(&mut self, voxels: &mut [Voxel]) {
         // Calculate fitness for all
         let mut fitness_scores: Vec<(usize, f64)> = voxels.iter()
             .enumerate()
@@ -109,16 +136,18 @@ impl EvolutionEngine {
         // Sort by fitness
         fitness_scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
         
+        self.generation += 1;
+        self.best_fitness = fitness_scores.first().map(|&(_, fitness)| fitness);
+        
         // Select top performers
         let top_count = (voxels.len() / 2).max(1);
-        let mut rng = rand::thread_rng();
         
         // Create new generation
         for i in top_count..voxels.len() {
-            let parent1_idx = fitness_scores[rng.gen_range(0..top_count)].0;
-            let parent2_idx = fitness_scores[rng.gen_range(0..top_count)].0;
+            let parent1_idx = fitness_scores[self.rng.gen_range(0..top_count)].0;
+            let parent2_idx = fitness_scores[self.rng.gen_range(0..top_count)].0;
             
-            if rng.gen_bool(self.crossover_rate) {
+            if self.rng.gen_bool(self.crossover_rate) {
                 // Crossover
                 let mut new_genome = self.combine(
                     &voxels[parent1_idx].genome,
@@ -140,3 +169,49 @@ impl Default for EvolutionEngine {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(seed: u64) -> (Vec<Vec<String>>, EvolutionEngine) {
+        let mut engine = EvolutionEngine::with_seed(seed);
+        engine.mutation_rate = 0.5;
+        let mut voxels: Vec<Voxel> = (0..8)
+            .map(|i| {
+                let mut voxel = Voxel::new([i, 0, 0]);
+                voxel.energy = i as f64 * 0.1;
+                voxel.genome.add_concept(format!("concept_{}", i));
+                voxel
+            })
+            .collect();
+        for _ in 0..10 {
+            engine.evolve(&mut voxels);
+        }
+        let genomes = voxels.into_iter().map(|v| v.genome.concepts).collect();
+        (genomes, engine)
+    }
+
+    #[test]
+    fn test_seeded_runs_are_reproducible() {
+        let (first, engine) = run(42);
+        let (second, _) = run(42);
+        let (other, _) = run(7);
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(engine.current_generation(), 10);
+        assert!(engine.best_fitness().is_some());
+    }
+
+    #[test]
+    fn test_clone_replays_rng_state() {
+        let mut engine = EvolutionEngine::new();
+        let mut clone = engine.clone();
+        let mut genome = Genome::new();
+        genome.add_concept("a".to_string());
+        genome.add_concept("b".to_string());
+        let first = engine.combine(&genome, &genome);
+        let second = clone.combine(&genome, &genome);
+        assert_eq!(first.concepts, second.concepts);
+    }
+}
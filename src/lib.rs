@@ -11,7 +11,7 @@ pub mod ui;
 pub mod voxel;
 
 // Re-export main types
-pub use archguard::ArchGuard;
+pub use archguard::{ArchGuard, ArchGuardConfig, RhythmEvent};
 pub use evolution::EvolutionEngine;
-pub use lighting::{LightPattern, LightingSystem};
+pub use lighting::{LightId, LightPattern, LightingSystem, PointLight};
 pub use voxel::{Voxel, VoxelWorld, Genome};
@@ -1,5 +1,4 @@
 use half::f16;
-use serde::{Deserialize, Serialize};
 
 /// LightPattern: exactly 1000 bytes
 #[repr(C, packed)]
@@ -87,26 +86,53 @@ impl Default for LightPattern {
     }
 }
 
-// Verify size is exactly 1000 bytes
-#[cfg(test)]
-mod tests {
-    use super::*;
-    
-    #[test]
-    fn test_light_pattern_size() {
-        assert_eq!(std::mem::size_of::<LightPattern>(), 1000);
+/// Handle returned by `LightingSystem::add_point_light`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LightId(u32);
+
+/// Point light with inverse-square falloff, cut off at `radius`
+#[derive(Debug, Clone, Copy)]
+pub struct PointLight {
+    pub id: LightId,
+    pub position: [f32; 3],
+    pub color: [f32; 3],
+    pub intensity: f32,
+    pub radius: f32,
+}
+
+impl PointLight {
+    /// Contribution at `position` (RGB)
+    pub fn contribution(&self, position: [f32; 3]) -> [f32; 3] {
+        let dx = position[0] - self.position[0];
+        let dy = position[1] - self.position[1];
+        let dz = position[2] - self.position[2];
+        let distance_sq = dx * dx + dy * dy + dz * dz;
+        if distance_sq > self.radius * self.radius {
+            return [0.0; 3];
+        }
+        // +1 keeps the falloff finite at the light's own position
+        let attenuation = self.intensity / (1.0 + distance_sq);
+        [
+            self.color[0] * attenuation,
+            self.color[1] * attenuation,
+            self.color[2] * attenuation,
+        ]
     }
 }
 
 /// Lighting System
 pub struct LightingSystem {
     pub patterns: Vec<LightPattern>,
+    point_lights: Vec<PointLight>,
+    next_light_id: u32,
 }
 
 impl LightingSystem {
     pub fn new() -> Self {
         Self {
             patterns: Vec::new(),
+            point_lights: Vec::new(),
+            next_light_id: 0,
         }
     }
     
@@ -114,6 +140,56 @@ impl LightingSystem {
         self.patterns.push(pattern);
     }
     
+    /// Add a point light; returns `None` unless `intensity` and `radius`
+    /// are finite and positive
+    pub fn add_point_light(
+        &mut self,
+        position: [f32; 3],
+        color: [f32; 3],
+        intensity: f32,
+        radius: f32,
+    ) -> Option<LightId> {
+        let valid = |v: f32| v.is_finite() && v > 0.0;
+        if !valid(intensity) || !valid(radius) {
+            return None;
+        }
+        let id = LightId(self.next_light_id);
+        self.next_light_id += 1;
+        self.point_lights.push(PointLight {
+            id,
+            position,
+            color,
+            intensity,
+            radius,
+        });
+        Some(id)
+    }
+    
+    /// Remove a point light; returns false if the id is unknown
+    pub fn remove_light(&mut self, id: LightId) -> bool {
+        match self.point_lights.iter().position(|light| light.id == id) {
+            Some(index) => {
+                self.point_lights.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+    
+    /// Active point lights, in insertion order
+    pub fn point_lights(&self) -> &[PointLight] {
+        &self.point_lights
+    }
+    
+    /// Sum of point light contributions at a voxel position (RGB)
+    pub fn calculate_voxel_lighting(&self, position: [i32; 3]) -> [f32; 3] {
+        let pos = [position[0] as f32, position[1] as f32, position[2] as f32];
+        self.point_lights.iter().fold([0.0; 3], |acc, light| {
+            let c = light.contribution(pos);
+            [acc[0] + c[0], acc[1] + c[1], acc[2] + c[2]]
+        })
+    }
+    
     pub fn update_lighting(&mut self, time: f32) {
         // Animate lighting patterns
         for pattern in &mut self.patterns {
@@ -129,3 +205,47 @@ impl Default for LightingSystem {
         Self::new()
     }
 }
+
+// Verify size is exactly 1000 bytes
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_light_pattern_size() {
+        assert_eq!(std::mem::size_of::<LightPattern>(), 1000);
+    }
+    
+    #[test]
+    fn test_point_light_falloff() {
+        let mut lighting = LightingSystem::new();
+        let id = lighting
+            .add_point_light([0.0, 0.0, 0.0], [1.0, 1.0, 1.0], 10.0, 8.0)
+            .unwrap();
+        
+        let near = lighting.calculate_voxel_lighting([1, 0, 0]);
+        let far = lighting.calculate_voxel_lighting([5, 0, 0]);
+        let outside = lighting.calculate_voxel_lighting([9, 0, 0]);
+        assert!(near[0] > far[0]);
+        assert!(far[0] > 0.0);
+        assert_eq!(outside, [0.0; 3]);
+        
+        assert!(lighting.remove_light(id));
+        assert!(!lighting.remove_light(id));
+        assert_eq!(lighting.calculate_voxel_lighting([1, 0, 0]), [0.0; 3]);
+    }
+    
+    #[test]
+    fn test_invalid_point_light_rejected() {
+        let mut lighting = LightingSystem::new();
+        let origin = [0.0, 0.0, 0.0];
+        let white = [1.0, 1.0, 1.0];
+        assert!(lighting.add_point_light(origin, white, 10.0, 0.0).is_none());
+        assert!(lighting.add_point_light(origin, white, 10.0, -8.0).is_none());
+        assert!(lighting.add_point_light(origin, white, 10.0, f32::NAN).is_none());
+        assert!(lighting.add_point_light(origin, white, f32::INFINITY, 8.0).is_none());
+        assert!(lighting.add_point_light(origin, white, 0.0, 8.0).is_none());
+        assert!(lighting.point_lights().is_empty());
+        assert_eq!(lighting.calculate_voxel_lighting([100, 0, 0]), [0.0; 3]);
+    }
+}
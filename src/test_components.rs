@@ -1,10 +1,16 @@
 // Simple test without GUI dependencies
+
+// Library modules are re-included here; this binary only exercises part of their API
+#[allow(dead_code)]
 #[path = "archguard.rs"]
 mod archguard;
+#[allow(dead_code)]
 #[path = "evolution.rs"]
 mod evolution;
+#[allow(dead_code)]
 #[path = "lighting.rs"]
 mod lighting;
+#[allow(dead_code)]
 #[path = "voxel.rs"]
 mod voxel;
 
@@ -34,7 +40,7 @@ fn main() {
     
     // Test 3: LightPattern size
     println!("\nTest 3: LightPattern Size");
-    let pattern = lighting::LightPattern::new();
+    let _pattern = lighting::LightPattern::new();
     let size = std::mem::size_of::<lighting::LightPattern>();
     println!("  ✓ LightPattern size: {} bytes", size);
     assert_eq!(size, 1000, "LightPattern must be exactly 1000 bytes!");
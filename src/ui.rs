@@ -1,4 +1,4 @@
-use crate::archguard::ArchGuard;
+use crate::archguard::{ArchGuard, RhythmEvent};
 use crate::evolution::EvolutionEngine;
 use crate::lighting::LightingSystem;
 use crate::voxel::VoxelWorld;
@@ -14,6 +14,7 @@ pub struct EngineUI {
     start_time: Instant,
     trauma_mode: bool,
     show_debug: bool,
+    last_rhythm_event: Option<RhythmEvent>,
     point_cloud_data: Vec<([f32; 3], [f32; 3])>,
 }
 
@@ -27,6 +28,7 @@ impl EngineUI {
             start_time: Instant::now(),
             trauma_mode: false,
             show_debug: true,
+            last_rhythm_event: None,
             point_cloud_data: Vec::new(),
         }
     }
@@ -44,8 +46,11 @@ impl eframe::App for EngineUI {
         // Update lighting
         self.lighting.update_lighting(elapsed as f32);
         
-        // Update rhythm detector
+        // Update rhythm detector and drain its events so the queue stays bounded
         self.archguard.update_rhythm(elapsed);
+        if let Some(event) = self.archguard.take_events().pop() {
+            self.last_rhythm_event = Some(event);
+        }
         
         // Get point cloud data
         self.point_cloud_data = self.world.get_point_cloud_data();
@@ -78,6 +83,9 @@ impl eframe::App for EngineUI {
             
             let rhythm_phase = self.archguard.get_rhythm_phase();
             ui.label(format!("Rhythm Phase (0.038 Hz): {:.3}", rhythm_phase));
+            if let Some(event) = &self.last_rhythm_event {
+                ui.label(format!("Last Rhythm Event: {:?}", event));
+            }
             
             // Evolution controls
             ui.separator();
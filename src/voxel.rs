@@ -1,7 +1,9 @@
 use bevy_ecs::prelude::*;
 use half::f16;
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
 
 /// Voxel component: 9-13 KB per voxel
 #[derive(Component, Clone)]
@@ -142,6 +144,12 @@ impl Genome {
     }
 }
 
+impl Default for Genome {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Voxel World System
 #[derive(Resource)]
 pub struct VoxelWorld {
@@ -169,7 +177,19 @@ impl VoxelWorld {
         self.voxels.push(entity);
         entity
     }
-    
+
+    /// Remove a component from an entity, returning it if it was attached
+    pub fn remove_component<T: Component>(&mut self, entity: Entity) -> Option<T> {
+        self.world.get_entity_mut(entity)?.take::<T>()
+    }
+
+    /// Despawn an entity and drop it from the voxel list
+    pub fn despawn(&mut self, entity: Entity) -> bool {
+        // Keep `voxels` in sync so update/point cloud never see stale IDs
+        self.voxels.retain(|&e| e != entity);
+        self.world.despawn(entity)
+    }
+
     pub fn update(&mut self, delta_time: f32) {
         // Update voxel physics and evolution
         // Use entity IDs to avoid borrowing issues
@@ -225,6 +245,32 @@ impl VoxelWorld {
         
         points
     }
+    
+    /// Export the point cloud as ASCII PLY (position + colour).
+    /// An empty world produces a valid file with zero vertices.
+    pub fn export_ply<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let points = self.get_point_cloud_data();
+        let mut out = BufWriter::new(File::create(path)?);
+        
+        writeln!(out, "ply")?;
+        writeln!(out, "format ascii 1.0")?;
+        writeln!(out, "comment Adaptive Entity Engine point cloud")?;
+        writeln!(out, "element vertex {}", points.len())?;
+        writeln!(out, "property float x")?;
+        writeln!(out, "property float y")?;
+        writeln!(out, "property float z")?;
+        writeln!(out, "property uchar red")?;
+        writeln!(out, "property uchar green")?;
+        writeln!(out, "property uchar blue")?;
+        writeln!(out, "end_header")?;
+        
+        for (pos, color) in &points {
+            let [r, g, b] = color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+            writeln!(out, "{} {} {} {} {} {}", pos[0], pos[1], pos[2], r, g, b)?;
+        }
+        
+        out.flush()
+    }
 }
 
 impl Default for VoxelWorld {
@@ -232,3 +278,64 @@ impl Default for VoxelWorld {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Tag;
+
+    #[test]
+    fn test_remove_component_and_despawn() {
+        let mut world = VoxelWorld::new();
+        let entity = world.add_voxel([1, 2, 3]);
+        let other = world.add_voxel([4, 5, 6]);
+        world.world.entity_mut(entity).insert(Tag);
+
+        let mut voxels = world.world.query::<&Voxel>();
+        let mut tagged = world.world.query::<(&Voxel, &Tag)>();
+        assert_eq!(voxels.iter(&world.world).count(), 2);
+        assert_eq!(tagged.iter(&world.world).count(), 1);
+
+        // Removing one component leaves the other in place
+        assert!(world.remove_component::<Tag>(entity).is_some());
+        assert!(world.remove_component::<Tag>(entity).is_none());
+        assert_eq!(tagged.iter(&world.world).count(), 0);
+        assert_eq!(voxels.iter(&world.world).count(), 2);
+
+        // Despawned entities are gone from queries and the voxel list
+        assert!(world.despawn(entity));
+        assert!(!world.despawn(entity));
+        assert_eq!(voxels.iter(&world.world).count(), 1);
+        assert_eq!(world.voxels, vec![other]);
+        assert!(world.remove_component::<Voxel>(entity).is_none());
+
+        world.update(0.016);
+        assert_eq!(world.get_point_cloud_data().len(), 1);
+    }
+
+    #[test]
+    fn test_export_ply() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("voxel_export_{}.ply", std::process::id()));
+
+        let mut world = VoxelWorld::new();
+        world.export_ply(&path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("element vertex 0\n"));
+        assert!(contents.ends_with("end_header\n"));
+
+        let entity = world.add_voxel([1, -2, 3]);
+        world.world.get_mut::<Voxel>(entity).unwrap().energy = 2.0;
+        world.add_voxel([0, 0, 0]);
+        world.export_ply(&path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(contents.starts_with("ply\nformat ascii 1.0\n"));
+        assert!(contents.contains("element vertex 2\n"));
+        let body: Vec<&str> = contents.split("end_header\n").nth(1).unwrap().lines().collect();
+        assert_eq!(body, vec!["1 -2 3 255 255 0", "0 0 0 0 0 0"]);
+    }
+}